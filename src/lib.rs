@@ -1,7 +1,7 @@
 //! An MPMC broadcast library.
 //!
-//! While [`async-channel`] provides a nice and simple multi-producer-multi-consumer channel, 
-//! this library provides a broadcasting feature, 
+//! While [`async-channel`] provides a nice and simple multi-producer-multi-consumer channel,
+//! this library provides a broadcasting feature,
 //! where every message sent on the channel is received by every receiver.
 //! Since the ownership of the data is transfered, the data is cloned for each receiver and hence
 //! [`Clone`] trait is required on the type of the data being transmitted.
@@ -23,16 +23,420 @@
 //! assert_eq!(receiver2.try_recv(), Ok(2));
 //! ```
 //!
-//! [`async_channel`]: https://crates.io/crates/async-channel 
+//! [`async-channel`]: https://crates.io/crates/async-channel
 
-use std::sync::{Arc, RwLock};
-use async_channel::{RecvError, SendError, TryRecvError, TrySendError};
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::task::{Context, Poll, Waker};
 
-type ChannelSenders<T> = Arc<RwLock<Vec<async_channel::Sender<T>>>>;
+use async_channel::TrySendError;
+use futures_core::Stream;
+use futures_sink::Sink;
+use pin_project_lite::pin_project;
+
+/// The error returned by [`Receiver::recv`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecvError {
+    /// The channel is closed (by [`Sender::close`] or by every [`Sender`] being dropped) and
+    /// empty.
+    Closed,
+    /// The receiver fell behind a sender using the overflow mode (see [`bounded_overflow`]) and
+    /// missed this many messages, which were dropped to let the sender keep making progress.
+    Lagged(u64),
+}
+
+impl fmt::Display for RecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecvError::Closed => write!(f, "receiving on an empty and closed channel"),
+            RecvError::Lagged(n) => write!(f, "receiver lagged behind and missed {n} messages"),
+        }
+    }
+}
+
+impl std::error::Error for RecvError {}
+
+/// The error returned by [`Receiver::try_recv`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryRecvError {
+    /// The channel is open but currently empty.
+    Empty,
+    /// The channel is closed (by [`Sender::close`] or by every [`Sender`] being dropped) and
+    /// empty.
+    Closed,
+    /// The receiver fell behind a sender using the overflow mode (see [`bounded_overflow`]) and
+    /// missed this many messages, which were dropped to let the sender keep making progress.
+    Lagged(u64),
+}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryRecvError::Empty => write!(f, "receiving on an empty channel"),
+            TryRecvError::Closed => write!(f, "receiving on an empty and closed channel"),
+            TryRecvError::Lagged(n) => write!(f, "receiver lagged behind and missed {n} messages"),
+        }
+    }
+}
+
+impl std::error::Error for TryRecvError {}
+
+/// How many messages, if any, a channel may buffer per receiver before it has to apply
+/// backpressure, and whether it instead drops old messages to keep producers unblocked.
+#[derive(Debug, Clone, Copy)]
+enum Capacity {
+    Bounded(usize),
+    Unbounded,
+    /// Like `Bounded`, but a full per-receiver buffer drops its oldest message instead of
+    /// blocking the sender; see [`bounded_overflow`].
+    Overflow(usize),
+}
+
+/// The state of one receiver of a [`bounded_overflow`] channel, shared with the sending side of
+/// its slot: a fixed-size ring buffer plus a count of messages dropped since the receiver last
+/// drained it.
+#[derive(Debug)]
+struct OverflowShared<T> {
+    queue: VecDeque<T>,
+    cap: usize,
+    missed: u64,
+    closed: bool,
+    /// Every task currently parked in `poll_recv`, woken in full (not just the first one) on
+    /// push/close so that multiple tasks polling the same `Receiver` concurrently (e.g. via
+    /// `Arc<Receiver>`) can't clobber each other's registration the way a single `Waker` slot
+    /// would.
+    wakers: Vec<Waker>,
+}
 
 #[derive(Clone, Debug)]
+struct OverflowSender<T>(Arc<Mutex<OverflowShared<T>>>);
+
+impl<T> OverflowSender<T> {
+    /// Buffer `msg`, dropping the oldest buffered message first if the ring is full.
+    ///
+    /// Only fails, returning `msg` back, if this slot has been closed.
+    fn push(&self, msg: T) -> Result<(), T> {
+        let mut shared = self.0.lock().expect("poisoned lock");
+        if shared.closed {
+            return Err(msg);
+        }
+
+        if shared.queue.len() >= shared.cap {
+            shared.queue.pop_front();
+            shared.missed += 1;
+        }
+        shared.queue.push_back(msg);
+
+        for waker in shared.wakers.drain(..) {
+            waker.wake();
+        }
+
+        Ok(())
+    }
+
+    fn close(&self) {
+        let mut shared = self.0.lock().expect("poisoned lock");
+        shared.closed = true;
+        for waker in shared.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+#[derive(Debug)]
+struct OverflowReceiver<T>(Arc<Mutex<OverflowShared<T>>>);
+
+impl<T> OverflowReceiver<T> {
+    fn poll_recv(&self, cx: &mut Context<'_>) -> Poll<Result<T, RecvError>> {
+        let mut shared = self.0.lock().expect("poisoned lock");
+
+        if shared.missed > 0 {
+            let missed = shared.missed;
+            shared.missed = 0;
+            return Poll::Ready(Err(RecvError::Lagged(missed)));
+        }
+
+        if let Some(msg) = shared.queue.pop_front() {
+            return Poll::Ready(Ok(msg));
+        }
+
+        if shared.closed {
+            return Poll::Ready(Err(RecvError::Closed));
+        }
+
+        if !shared.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            shared.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+
+    fn try_recv(&self) -> Result<T, TryRecvError> {
+        let mut shared = self.0.lock().expect("poisoned lock");
+
+        if shared.missed > 0 {
+            let missed = shared.missed;
+            shared.missed = 0;
+            return Err(TryRecvError::Lagged(missed));
+        }
+
+        match shared.queue.pop_front() {
+            Some(msg) => Ok(msg),
+            None if shared.closed => Err(TryRecvError::Closed),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    fn recv(&self) -> OverflowRecv<'_, T> {
+        OverflowRecv { receiver: self }
+    }
+
+    fn is_closed(&self) -> bool {
+        self.0.lock().expect("poisoned lock").closed
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.lock().expect("poisoned lock").queue.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.0.lock().expect("poisoned lock").queue.len()
+    }
+}
+
+struct OverflowRecv<'r, T> {
+    receiver: &'r OverflowReceiver<T>,
+}
+
+impl<'r, T> Future for OverflowRecv<'r, T> {
+    type Output = Result<T, RecvError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+#[derive(Clone)]
+enum InnerSender<T> {
+    Channel(async_channel::Sender<T>),
+    Overflow(OverflowSender<T>),
+    /// Stands in for a deactivated [`InactiveReceiver`]'s slot: accepts every send without
+    /// buffering anything, so the receiver keeps being counted without imposing backpressure.
+    Inactive,
+}
+
+impl<T: fmt::Debug> fmt::Debug for InnerSender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InnerSender::Channel(sender) => f.debug_tuple("Channel").field(sender).finish(),
+            InnerSender::Overflow(sender) => f.debug_tuple("Overflow").field(sender).finish(),
+            InnerSender::Inactive => f.debug_tuple("Inactive").finish(),
+        }
+    }
+}
+
+pin_project! {
+    #[project = InnerReceiverProj]
+    #[derive(Debug)]
+    enum InnerReceiver<T> {
+        Channel {
+            // `async_channel::Receiver` opts out of `Unpin` (to leave room for future
+            // optimizations), so this needs to stay structurally pinned to poll it as a
+            // `Stream`.
+            #[pin]
+            receiver: async_channel::Receiver<T>,
+        },
+        Overflow {
+            receiver: OverflowReceiver<T>,
+        },
+    }
+}
+
+/// Close the inner channel backing a single subscription slot, if it has one.
+fn close_sender<T>(sender: &InnerSender<T>) {
+    match sender {
+        InnerSender::Channel(sender) => {
+            sender.close();
+        }
+        InnerSender::Overflow(sender) => sender.close(),
+        InnerSender::Inactive => {}
+    }
+}
+
+fn new_channel<T>(capacity: Capacity) -> (InnerSender<T>, InnerReceiver<T>) {
+    match capacity {
+        Capacity::Bounded(cap) => {
+            let (sender, receiver) = async_channel::bounded(cap);
+            (InnerSender::Channel(sender), InnerReceiver::Channel { receiver })
+        }
+        Capacity::Unbounded => {
+            let (sender, receiver) = async_channel::unbounded();
+            (InnerSender::Channel(sender), InnerReceiver::Channel { receiver })
+        }
+        Capacity::Overflow(cap) => {
+            let shared = Arc::new(Mutex::new(OverflowShared {
+                queue: VecDeque::with_capacity(cap),
+                cap,
+                missed: 0,
+                closed: false,
+                wakers: Vec::new(),
+            }));
+
+            (
+                InnerSender::Overflow(OverflowSender(shared.clone())),
+                InnerReceiver::Overflow { receiver: OverflowReceiver(shared) },
+            )
+        }
+    }
+}
+
+/// An entry in [`Shared::senders`], tagged with the id of the [`Receiver`] it feeds so that a
+/// dropped receiver's half of the channel can be found and removed again.
+#[derive(Debug)]
+struct TaggedSender<T> {
+    id: u64,
+    sender: InnerSender<T>,
+}
+
+#[derive(Debug)]
+struct Shared<T> {
+    senders: RwLock<Vec<TaggedSender<T>>>,
+    next_id: AtomicU64,
+    sender_count: AtomicUsize,
+    closed: AtomicBool,
+}
+
+impl<T> Shared<T> {
+    fn new(sender: InnerSender<T>) -> Self {
+        Self {
+            senders: RwLock::new(vec![TaggedSender { id: 0, sender }]),
+            next_id: AtomicU64::new(1),
+            sender_count: AtomicUsize::new(1),
+            closed: AtomicBool::new(false),
+        }
+    }
+
+    /// Add `sender` as a new subscription slot, returning its id.
+    ///
+    /// If [`Shared::close`] has already run (or races with this call), the new slot is closed
+    /// too: [`Shared::close`] only closes the senders it can see under its own lock acquisition,
+    /// so this re-checks `closed` under the same lock that guards the push to avoid a window
+    /// where a slot added concurrently with `close` is missed by both.
+    fn push(&self, sender: InnerSender<T>) -> u64 {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let mut senders = self.senders.write().expect("poisoned lock");
+        senders.push(TaggedSender { id, sender });
+        if self.closed.load(Ordering::SeqCst) {
+            close_sender(&senders.last().expect("just pushed").sender);
+        }
+
+        id
+    }
+
+    fn remove(&self, id: u64) {
+        self.senders
+            .write()
+            .expect("poisoned lock")
+            .retain(|tagged| tagged.id != id);
+    }
+
+    fn deactivate(&self, id: u64) {
+        let mut senders = self.senders.write().expect("poisoned lock");
+        if let Some(tagged) = senders.iter_mut().find(|tagged| tagged.id == id) {
+            tagged.sender = InnerSender::Inactive;
+        }
+    }
+
+    /// Swap the (presumably [`InnerSender::Inactive`]) slot `id` back for a real `sender`.
+    ///
+    /// Re-checks `closed` under the same lock for the same reason [`Shared::push`] does.
+    fn reactivate(&self, id: u64, sender: InnerSender<T>) {
+        let mut senders = self.senders.write().expect("poisoned lock");
+        if let Some(tagged) = senders.iter_mut().find(|tagged| tagged.id == id) {
+            tagged.sender = sender;
+            if self.closed.load(Ordering::SeqCst) {
+                close_sender(&tagged.sender);
+            }
+        }
+    }
+
+    fn receiver_count(&self) -> usize {
+        self.senders.read().expect("poisoned lock").len()
+    }
+
+    /// Close every inner channel, returning `true` if this call is the one that closed the
+    /// channel (i.e. it wasn't already closed).
+    fn close(&self) -> bool {
+        let newly_closed = !self.closed.swap(true, Ordering::SeqCst);
+
+        for tagged in self.senders.read().expect("poisoned lock").iter() {
+            close_sender(&tagged.sender);
+        }
+
+        newly_closed
+    }
+
+    fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::SeqCst)
+    }
+}
+
+type ChannelSenders<T> = Arc<Shared<T>>;
+
+/// One inner channel's send, in flight as part of a [`PendingBroadcast`].
+type BroadcastSend<T> = Pin<Box<dyn Future<Output = Result<(), async_channel::SendError<T>>> + Send>>;
+
+/// The state backing the [`Sink`] impl of [`Sender`] while it drives a broadcast across all the
+/// inner channels to completion.
+///
+/// `item` is boxed so `PendingBroadcast<T>`, and in turn `Sender<T>`, stays `Unpin` regardless of
+/// whether `T` is, which `poll_ready`/`start_send`/`poll_flush` rely on to get an unpinned `&mut
+/// Sender<T>` out of their `Pin<&mut Self>`.
+struct PendingBroadcast<T> {
+    item: Box<T>,
+    any_ok: bool,
+    futures: Vec<BroadcastSend<T>>,
+}
+
 pub struct Sender<T> {
     channel_senders: ChannelSenders<T>,
+    capacity: Capacity,
+    pending: Option<PendingBroadcast<T>>,
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.channel_senders
+            .sender_count
+            .fetch_add(1, Ordering::SeqCst);
+
+        Self {
+            channel_senders: self.channel_senders.clone(),
+            capacity: self.capacity,
+            // A clone starts out with no in-flight broadcast of its own.
+            pending: None,
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        if self.channel_senders.sender_count.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.channel_senders.close();
+        }
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender")
+            .field("capacity", &self.capacity)
+            .field("pending", &self.pending.is_some())
+            .finish()
+    }
 }
 
 impl<T> Sender<T>
@@ -40,38 +444,321 @@ where
     T: Clone,
 {
     pub fn try_send(&self, msg: T) -> Result<(), TrySendError<T>> {
-        for sender in self.channel_senders.read().expect("poisoned lock").iter() {
-            sender.try_send(msg.clone())?;
+        if self.channel_senders.is_closed() {
+            return Err(TrySendError::Closed(msg));
         }
 
-        Ok(())
+        let mut disconnected = Vec::new();
+        let mut sent = false;
+
+        for tagged in self.channel_senders.senders.read().expect("poisoned lock").iter() {
+            match &tagged.sender {
+                InnerSender::Channel(sender) => match sender.try_send(msg.clone()) {
+                    Ok(()) => sent = true,
+                    Err(TrySendError::Closed(_)) => disconnected.push(tagged.id),
+                    Err(err) => return Err(err),
+                },
+                InnerSender::Overflow(sender) => match sender.push(msg.clone()) {
+                    Ok(()) => sent = true,
+                    Err(_) => disconnected.push(tagged.id),
+                },
+                InnerSender::Inactive => sent = true,
+            }
+        }
+
+        self.prune(disconnected);
+
+        if sent {
+            Ok(())
+        } else {
+            Err(TrySendError::Closed(msg))
+        }
     }
 
-    pub async fn send(&self, msg: T) -> Result<(), SendError<T>> {
-        for sender in self.channel_senders.read().expect("poisoned lock").iter() {
-            sender.send(msg.clone()).await?;
+    pub async fn send(&self, msg: T) -> Result<(), async_channel::SendError<T>> {
+        if self.channel_senders.is_closed() {
+            return Err(async_channel::SendError(msg));
         }
 
-        Ok(())
+        let mut disconnected = Vec::new();
+        let mut sent = false;
+
+        // Collect the inner senders up-front so the lock isn't held across the `.await` points.
+        let senders: Vec<_> = self
+            .channel_senders
+            .senders
+            .read()
+            .expect("poisoned lock")
+            .iter()
+            .map(|tagged| (tagged.id, tagged.sender.clone()))
+            .collect();
+
+        for (id, sender) in senders {
+            match sender {
+                InnerSender::Channel(sender) => match sender.send(msg.clone()).await {
+                    Ok(()) => sent = true,
+                    Err(async_channel::SendError(_)) => disconnected.push(id),
+                },
+                InnerSender::Overflow(sender) => match sender.push(msg.clone()) {
+                    Ok(()) => sent = true,
+                    Err(_) => disconnected.push(id),
+                },
+                InnerSender::Inactive => sent = true,
+            }
+        }
+
+        self.prune(disconnected);
+
+        if sent {
+            Ok(())
+        } else {
+            Err(async_channel::SendError(msg))
+        }
+    }
+
+    /// Create a new [`Receiver`], that will receive every message sent after this call.
+    pub fn subscribe(&self) -> Receiver<T> {
+        let (sender, receiver) = new_channel(self.capacity);
+        let id = self.channel_senders.push(sender);
+
+        Receiver {
+            channel_senders: self.channel_senders.clone(),
+            receiver,
+            capacity: self.capacity,
+            id,
+            active: true,
+        }
+    }
+
+    fn prune(&self, disconnected: Vec<u64>) {
+        if disconnected.is_empty() {
+            return;
+        }
+
+        self.channel_senders
+            .senders
+            .write()
+            .expect("poisoned lock")
+            .retain(|tagged| !disconnected.contains(&tagged.id));
+    }
+
+    /// The number of live receivers, including [`InactiveReceiver`]s.
+    pub fn receiver_count(&self) -> usize {
+        self.channel_senders.receiver_count()
+    }
+
+    /// Close every inner channel, so every [`Receiver`] drains whatever is left buffered for it
+    /// and then gets [`RecvError::Closed`]/[`TryRecvError::Closed`].
+    ///
+    /// Returns `true` if this call is the one that closed the channel.
+    pub fn close(&self) -> bool {
+        self.channel_senders.close()
+    }
+
+    /// Whether the channel has been closed, by [`Sender::close`] or by closing the [`Sink`].
+    pub fn is_closed(&self) -> bool {
+        self.channel_senders.is_closed()
     }
 
     // TODO: More applicable API from async_channel::Sender.
 }
 
-#[derive(Debug)]
-pub struct Receiver<T> {
-    channel_senders: ChannelSenders<T>,
-    receiver: async_channel::Receiver<T>,
-    cap: Option<usize>,
+impl<T> Sink<T> for Sender<T>
+where
+    T: Clone + Send + 'static,
+{
+    type Error = async_channel::SendError<T>;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if self.pending.is_some() {
+            self.as_mut().poll_flush(cx)
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+        assert!(
+            this.pending.is_none(),
+            "start_send called without driving the previous item to completion with poll_ready/poll_flush"
+        );
+
+        // Mirror the is_closed() check try_send()/send() already do up front: otherwise a
+        // closed channel with at least one InactiveReceiver would report Ok(()) here, since the
+        // `Inactive` arm below has no closed state of its own to consult.
+        if this.channel_senders.is_closed() {
+            return Err(async_channel::SendError(item));
+        }
+
+        let futures = this
+            .channel_senders
+            .senders
+            .read()
+            .expect("poisoned lock")
+            .iter()
+            .map(|tagged| {
+                let sender = tagged.sender.clone();
+                let item = item.clone();
+                Box::pin(async move {
+                    match sender {
+                        InnerSender::Channel(sender) => sender.send(item).await,
+                        InnerSender::Overflow(sender) => {
+                            sender.push(item).map_err(async_channel::SendError)
+                        }
+                        InnerSender::Inactive => Ok(()),
+                    }
+                }) as BroadcastSend<T>
+            })
+            .collect();
+
+        this.pending = Some(PendingBroadcast {
+            item: Box::new(item),
+            any_ok: false,
+            futures,
+        });
+
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+
+        let pending = match this.pending.as_mut() {
+            Some(pending) => pending,
+            None => return Poll::Ready(Ok(())),
+        };
+
+        let mut i = 0;
+        while i < pending.futures.len() {
+            match pending.futures[i].as_mut().poll(cx) {
+                Poll::Ready(Ok(())) => {
+                    pending.any_ok = true;
+                    drop(pending.futures.remove(i));
+                }
+                Poll::Ready(Err(_)) => {
+                    drop(pending.futures.remove(i));
+                }
+                Poll::Pending => i += 1,
+            }
+        }
+
+        if !pending.futures.is_empty() {
+            return Poll::Pending;
+        }
+
+        let pending = this.pending.take().expect("just checked Some above");
+        if pending.any_ok {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Ready(Err(async_channel::SendError(*pending.item)))
+        }
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self.as_mut().poll_flush(cx) {
+            Poll::Ready(result) => {
+                // Close the channel so start_send rejects anything sent after this, same as
+                // Sender::close() does; a Sink that reported itself closed but still silently
+                // accepted sends afterwards would violate the trait's contract.
+                self.channel_senders.close();
+                Poll::Ready(result)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+pin_project! {
+    #[derive(Debug)]
+    pub struct Receiver<T> {
+        channel_senders: ChannelSenders<T>,
+        // `async_channel::Receiver` opts out of `Unpin` (to leave room for future
+        // optimizations), so this needs to stay structurally pinned to poll it as a `Stream`.
+        #[pin]
+        receiver: InnerReceiver<T>,
+        capacity: Capacity,
+        id: u64,
+        // Set to `false` by `deactivate`, so `Drop` leaves the now-`InactiveReceiver`-owned slot
+        // in `channel_senders` alone instead of removing it.
+        active: bool,
+    }
+
+    impl<T> PinnedDrop for Receiver<T> {
+        fn drop(this: Pin<&mut Self>) {
+            let this = this.project();
+            if *this.active {
+                this.channel_senders.remove(*this.id);
+            }
+        }
+    }
 }
 
 impl<T> Receiver<T> {
     pub fn try_recv(&self) -> Result<T, TryRecvError> {
-        self.receiver.try_recv()
+        match &self.receiver {
+            InnerReceiver::Channel { receiver } => receiver.try_recv().map_err(|err| match err {
+                async_channel::TryRecvError::Empty => TryRecvError::Empty,
+                async_channel::TryRecvError::Closed => TryRecvError::Closed,
+            }),
+            InnerReceiver::Overflow { receiver } => receiver.try_recv(),
+        }
     }
 
     pub async fn recv(&self) -> Result<T, RecvError> {
-        self.receiver.recv().await
+        match &self.receiver {
+            InnerReceiver::Channel { receiver } => receiver.recv().await.map_err(|_| RecvError::Closed),
+            InnerReceiver::Overflow { receiver } => receiver.recv().await,
+        }
+    }
+
+    /// The number of [`Sender`]s feeding this channel.
+    pub fn sender_count(&self) -> usize {
+        self.channel_senders.sender_count.load(Ordering::SeqCst)
+    }
+
+    /// Whether the channel has been closed, by [`Sender::close`] or by every [`Sender`] being
+    /// dropped.
+    ///
+    /// A closed receiver may still have messages buffered for it; check [`Receiver::is_empty`]
+    /// to tell a closed-but-draining channel apart from one that's fully drained.
+    pub fn is_closed(&self) -> bool {
+        match &self.receiver {
+            InnerReceiver::Channel { receiver } => receiver.is_closed(),
+            InnerReceiver::Overflow { receiver } => receiver.is_closed(),
+        }
+    }
+
+    /// Whether this receiver currently has no buffered messages.
+    pub fn is_empty(&self) -> bool {
+        match &self.receiver {
+            InnerReceiver::Channel { receiver } => receiver.is_empty(),
+            InnerReceiver::Overflow { receiver } => receiver.is_empty(),
+        }
+    }
+
+    /// The number of messages currently buffered for this receiver.
+    pub fn len(&self) -> usize {
+        match &self.receiver {
+            InnerReceiver::Channel { receiver } => receiver.len(),
+            InnerReceiver::Overflow { receiver } => receiver.len(),
+        }
+    }
+
+    /// Give up this receiver's buffer in exchange for a handle that keeps its subscription slot
+    /// alive without receiving or buffering any messages.
+    ///
+    /// Call [`InactiveReceiver::activate`] to turn it back into a receiving [`Receiver`].
+    pub fn deactivate(mut self) -> InactiveReceiver<T> {
+        self.active = false;
+        self.channel_senders.deactivate(self.id);
+
+        InactiveReceiver {
+            channel_senders: self.channel_senders.clone(),
+            capacity: self.capacity,
+            id: self.id,
+            owns_slot: true,
+        }
     }
 
     // TODO: More applicable API from async_channel::Receiver.
@@ -79,45 +766,128 @@ impl<T> Receiver<T> {
 
 impl<T> Clone for Receiver<T> {
     fn clone(&self) -> Self {
-        let (sender, receiver) = match self.cap {
-            Some(cap) => async_channel::bounded(cap),
-            None => async_channel::unbounded(),
-        };
-
-        let channel_senders = self.channel_senders.clone();
-        channel_senders.write().expect("poisoned lock").push(sender);
+        let (sender, receiver) = new_channel(self.capacity);
+        let id = self.channel_senders.push(sender);
 
         Self {
             channel_senders: self.channel_senders.clone(),
             receiver,
-            cap: self.cap,
+            capacity: self.capacity,
+            id,
+            active: true,
+        }
+    }
+}
+
+/// A handle that keeps a [`Sender`]/[`Receiver`] channel's subscription slot alive without
+/// actually receiving or buffering any messages, created via [`Receiver::deactivate`].
+///
+/// Sends still count this as a live receiver (so [`Sender::receiver_count`] includes it and a
+/// broadcast never fails just because every *active* receiver is gone), but the messages
+/// themselves are discarded rather than buffered, so an inactive receiver costs no memory and
+/// never applies backpressure.
+#[derive(Debug)]
+pub struct InactiveReceiver<T> {
+    channel_senders: ChannelSenders<T>,
+    capacity: Capacity,
+    id: u64,
+    // Set to `false` by `activate`, so `Drop` leaves the slot alone instead of removing the one
+    // it just handed off to a `Receiver`.
+    owns_slot: bool,
+}
+
+impl<T> InactiveReceiver<T> {
+    /// Turn this handle back into a [`Receiver`] that receives every message sent after this
+    /// call.
+    pub fn activate(mut self) -> Receiver<T> {
+        let (sender, receiver) = new_channel(self.capacity);
+        self.channel_senders.reactivate(self.id, sender);
+        self.owns_slot = false;
+
+        Receiver {
+            channel_senders: self.channel_senders.clone(),
+            receiver,
+            capacity: self.capacity,
+            id: self.id,
+            active: true,
+        }
+    }
+
+    /// The number of [`Sender`]s feeding this channel.
+    pub fn sender_count(&self) -> usize {
+        self.channel_senders.sender_count.load(Ordering::SeqCst)
+    }
+}
+
+impl<T> Drop for InactiveReceiver<T> {
+    fn drop(&mut self) {
+        if self.owns_slot {
+            self.channel_senders.remove(self.id);
+        }
+    }
+}
+
+impl<T> Stream for Receiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut this = self.project();
+        loop {
+            return match this.receiver.as_mut().project() {
+                InnerReceiverProj::Channel { receiver } => receiver.poll_next(cx),
+                InnerReceiverProj::Overflow { receiver } => match receiver.poll_recv(cx) {
+                    Poll::Ready(Ok(msg)) => Poll::Ready(Some(msg)),
+                    Poll::Ready(Err(RecvError::Closed)) => Poll::Ready(None),
+                    // The `Stream` item type can't carry the missed count, so skip past the
+                    // lag notification and keep polling for the next real message.
+                    Poll::Ready(Err(RecvError::Lagged(_))) => continue,
+                    Poll::Pending => Poll::Pending,
+                },
+            };
         }
     }
 }
 
 pub fn bounded<T>(cap: usize) -> (Sender<T>, Receiver<T>) {
-    broadcast_channel(Some(cap))
+    broadcast_channel(Capacity::Bounded(cap))
 }
 
 pub fn unbounded<T>() -> (Sender<T>, Receiver<T>) {
-    broadcast_channel(None)
+    broadcast_channel(Capacity::Unbounded)
+}
+
+/// Create a channel where a receiver that falls behind never blocks its sender.
+///
+/// Each receiver keeps its own ring buffer of at most `cap` messages. When a send would
+/// overflow that ring, the oldest buffered message is dropped instead, and the receiver's next
+/// [`Receiver::recv`]/[`Receiver::try_recv`] call returns [`RecvError::Lagged`] (respectively
+/// [`TryRecvError::Lagged`]) telling it how many messages it missed.
+///
+/// # Panics
+///
+/// Panics if `cap` is `0`, same as [`bounded`].
+pub fn bounded_overflow<T>(cap: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(cap > 0, "capacity cannot be zero");
+
+    broadcast_channel(Capacity::Overflow(cap))
 }
 
-fn broadcast_channel<T>(cap: Option<usize>) -> (Sender<T>, Receiver<T>) {
-    let (sender, receiver) = match cap {
-        Some(cap) => async_channel::bounded(cap),
-        None => async_channel::unbounded(),
-    };
-    let channel_senders = Arc::new(RwLock::new(vec![sender]));
+fn broadcast_channel<T>(capacity: Capacity) -> (Sender<T>, Receiver<T>) {
+    let (sender, receiver) = new_channel(capacity);
+    let channel_senders = Arc::new(Shared::new(sender));
 
     (
-        Sender { 
+        Sender {
             channel_senders: channel_senders.clone(),
+            capacity,
+            pending: None,
         },
         Receiver {
             channel_senders,
             receiver,
-            cap,
+            capacity,
+            id: 0,
+            active: true,
         },
     )
 }
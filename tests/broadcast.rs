@@ -0,0 +1,18 @@
+use async_broadcast::bounded;
+use futures_lite::future;
+
+#[test]
+fn dropping_a_receiver_prunes_its_slot() {
+    let (s, r1) = bounded(1);
+    let r2 = r1.clone();
+    assert_eq!(s.receiver_count(), 2);
+
+    drop(r2);
+    assert_eq!(s.receiver_count(), 1);
+
+    // With `r2` gone, broadcasting still succeeds and `r1` gets every message.
+    future::block_on(s.send(1)).unwrap();
+    assert_eq!(future::block_on(r1.recv()), Ok(1));
+    future::block_on(s.send(2)).unwrap();
+    assert_eq!(future::block_on(r1.recv()), Ok(2));
+}
@@ -0,0 +1,34 @@
+use async_broadcast::bounded;
+use futures_lite::future;
+
+#[test]
+fn deactivate_then_reactivate_keeps_receiving() {
+    let (s, r1) = bounded(4);
+    let r2 = r1.clone();
+    assert_eq!(s.receiver_count(), 2);
+
+    let inactive = r2.deactivate();
+    // Still counted, even though it's not buffering anything for itself.
+    assert_eq!(s.receiver_count(), 2);
+
+    s.try_send(1).unwrap();
+    assert_eq!(future::block_on(r1.recv()), Ok(1));
+
+    let r2 = inactive.activate();
+    assert_eq!(s.receiver_count(), 2);
+
+    // Only messages sent after reactivation show up; deactivation dropped anything in between.
+    s.try_send(2).unwrap();
+    assert_eq!(future::block_on(r2.recv()), Ok(2));
+}
+
+#[test]
+fn close_reaches_a_deactivated_receiver() {
+    let (s, r1) = bounded::<i32>(4);
+    let inactive = r1.clone().deactivate();
+
+    s.close();
+
+    let r2 = inactive.activate();
+    assert_eq!(future::block_on(r2.recv()), Err(async_broadcast::RecvError::Closed));
+}
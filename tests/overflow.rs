@@ -0,0 +1,64 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Wake, Waker};
+
+use async_broadcast::{bounded_overflow, RecvError};
+
+#[test]
+fn full_ring_drops_oldest_and_reports_how_many_were_missed() {
+    let (s, r) = bounded_overflow(2);
+
+    s.try_send(1).unwrap();
+    s.try_send(2).unwrap();
+    // The ring only holds 2, so this overflows and `1` is dropped to make room.
+    s.try_send(3).unwrap();
+
+    assert_eq!(futures_lite::future::block_on(r.recv()), Err(RecvError::Lagged(1)));
+    assert_eq!(futures_lite::future::block_on(r.recv()), Ok(2));
+    assert_eq!(futures_lite::future::block_on(r.recv()), Ok(3));
+}
+
+#[test]
+#[should_panic(expected = "capacity cannot be zero")]
+fn zero_capacity_panics() {
+    let _ = bounded_overflow::<i32>(0);
+}
+
+struct FlagWaker(AtomicBool);
+
+impl Wake for FlagWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn concurrent_pollers_on_the_same_receiver_all_get_woken() {
+    // A shared `Arc<Receiver>` polled by two tasks is exactly the scenario a single `Waker`
+    // slot (rather than a list) would get wrong: the second registration would clobber the
+    // first's, leaving it parked forever even though a message arrived for it.
+    let (s, r) = bounded_overflow::<i32>(4);
+
+    let mut fut_a = Box::pin(r.recv());
+    let mut fut_b = Box::pin(r.recv());
+
+    let waker_a = Arc::new(FlagWaker(AtomicBool::new(false)));
+    let waker_b = Arc::new(FlagWaker(AtomicBool::new(false)));
+    let raw_waker_a = Waker::from(waker_a.clone());
+    let raw_waker_b = Waker::from(waker_b.clone());
+    let mut cx_a = Context::from_waker(&raw_waker_a);
+    let mut cx_b = Context::from_waker(&raw_waker_b);
+
+    assert!(fut_a.as_mut().poll(&mut cx_a).is_pending());
+    assert!(fut_b.as_mut().poll(&mut cx_b).is_pending());
+
+    s.try_send(1).unwrap();
+
+    assert!(waker_a.0.load(Ordering::SeqCst));
+    assert!(waker_b.0.load(Ordering::SeqCst));
+}
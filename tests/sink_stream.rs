@@ -0,0 +1,33 @@
+use async_broadcast::bounded;
+use futures_lite::future;
+use futures_util::{SinkExt, StreamExt};
+
+#[test]
+fn sink_send_reaches_every_stream_receiver() {
+    let (mut s, r1) = bounded(4);
+    let r2 = r1.clone();
+    let mut r1 = Box::pin(r1);
+    let mut r2 = Box::pin(r2);
+
+    future::block_on(SinkExt::send(&mut s, 1)).unwrap();
+    future::block_on(SinkExt::send(&mut s, 2)).unwrap();
+
+    assert_eq!(future::block_on(r1.next()), Some(1));
+    assert_eq!(future::block_on(r1.next()), Some(2));
+
+    assert_eq!(future::block_on(r2.next()), Some(1));
+    assert_eq!(future::block_on(r2.next()), Some(2));
+}
+
+#[test]
+fn sink_close_rejects_further_sends() {
+    let (mut s, r) = bounded(4);
+    let mut r = Box::pin(r);
+
+    future::block_on(SinkExt::send(&mut s, 1)).unwrap();
+    future::block_on(SinkExt::close(&mut s)).unwrap();
+
+    assert!(future::block_on(SinkExt::send(&mut s, 2)).is_err());
+    assert_eq!(future::block_on(r.next()), Some(1));
+    assert_eq!(future::block_on(r.next()), None);
+}
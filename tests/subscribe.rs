@@ -0,0 +1,20 @@
+use async_broadcast::bounded;
+use futures_lite::future;
+
+#[test]
+fn subscribe_only_sees_messages_sent_after_it_was_created() {
+    let (s, r1) = bounded(4);
+
+    s.try_send(1).unwrap();
+
+    let r2 = s.subscribe();
+    assert_eq!(s.receiver_count(), 2);
+
+    s.try_send(2).unwrap();
+
+    assert_eq!(future::block_on(r1.recv()), Ok(1));
+    assert_eq!(future::block_on(r1.recv()), Ok(2));
+
+    // `r2` missed the first message; it only exists from the second one onward.
+    assert_eq!(future::block_on(r2.recv()), Ok(2));
+}